@@ -1,17 +1,42 @@
 //! Ergonomic and thread safe version of Box<dyn Any>.
 
 
-use std::{any::{Any, TypeId}, fmt::Debug, mem};
+use std::{any::{Any, TypeId}, collections::HashMap, fmt::{self, Debug, Formatter}, mem, sync::{Mutex, OnceLock}};
+#[cfg(feature = "ord")]
+use std::cmp::Ordering;
+#[cfg(feature = "hash")]
+use std::hash::{Hash, Hasher};
 use downcast_rs::{impl_downcast, Downcast};
 
 const _: Option<Box<dyn DynObject>> = None;
 
 /// A type that can be boxed into a [`Object`].
-/// 
+///
 /// The trait bounds [`Clone`], [`Debug`] and [`PartialEq`] are required for maximum usability.
 pub trait DynObject: Downcast + Debug + Send + Sync + 'static {
     fn dyn_clone(&self) -> Box<dyn DynObject>;
     fn dyn_eq(&self, other: &dyn DynObject) -> bool;
+    /// Hash the value, mixing in its [`TypeId`] so distinct types don't collide.
+    ///
+    /// Requires the `hash` feature, which adds a `Hash` bound to the blanket impl.
+    #[cfg(feature = "hash")]
+    fn dyn_hash(&self, state: &mut dyn Hasher);
+    /// Produce a total ordering against another value of any concrete type.
+    ///
+    /// Equal concrete types compare by value; otherwise the two [`TypeId`]s are compared to
+    /// yield a stable, arbitrary ordering. Requires the `ord` feature, which adds an `Ord`
+    /// bound to the blanket impl.
+    #[cfg(feature = "ord")]
+    fn dyn_cmp(&self, other: &dyn DynObject) -> Ordering;
+    /// The [`TypeId`] that identifies this value for cross-type ordering.
+    ///
+    /// Defaults to the concrete type; a deferred value overrides it to the type it materializes
+    /// to, so a lazy and an eager object of the same type order identically against any third
+    /// type. Requires the `ord` feature.
+    #[cfg(feature = "ord")]
+    fn value_type_id(&self) -> TypeId {
+        self.as_any().type_id()
+    }
 }
 
 impl_downcast!(DynObject);
@@ -28,19 +53,55 @@ impl PartialEq for dyn DynObject {
     }
 }
 
-impl<T> DynObject for T where T: Debug + Clone + PartialEq + Send + Sync + 'static{
-    fn dyn_clone(&self) -> Box<dyn DynObject> {
-        Box::new(self.clone())
-    }
+// The `hash` and `ord` features each add a bound to the blanket impl; since a method can only be
+// defined in the same impl block as its siblings, every combination of the two needs its own block.
+macro_rules! blanket_dyn_object {
+    ($($bound:ident +)*) => {
+        impl<T> DynObject for T where T: Debug + Clone + PartialEq $(+ $bound)* + Send + Sync + 'static {
+            fn dyn_clone(&self) -> Box<dyn DynObject> {
+                Box::new(self.clone())
+            }
 
-    fn dyn_eq(&self, other: &dyn DynObject) -> bool {
-        match other.downcast_ref::<T>() {
-            Some(some) => some == self,
-            None => false,
+            fn dyn_eq(&self, other: &dyn DynObject) -> bool {
+                match other.downcast_ref::<T>() {
+                    Some(some) => some == self,
+                    // Recognize a deferred `Lazy<T>` so equality stays symmetric with a lazy
+                    // object on either side (forcing it if necessary).
+                    None => other.downcast_ref::<Lazy<T>>().is_some_and(|other| other.force() == self),
+                }
+            }
+
+            #[cfg(feature = "hash")]
+            fn dyn_hash(&self, mut state: &mut dyn Hasher) {
+                TypeId::of::<T>().hash(&mut state);
+                self.hash(&mut state);
+            }
+
+            #[cfg(feature = "ord")]
+            fn dyn_cmp(&self, other: &dyn DynObject) -> Ordering {
+                if let Some(other) = other.downcast_ref::<T>() {
+                    self.cmp(other)
+                } else if let Some(other) = other.downcast_ref::<Lazy<T>>() {
+                    // Reconcile with a deferred `Lazy<T>` so ordering agrees with `dyn_eq` and
+                    // stays antisymmetric regardless of which side is lazy.
+                    self.cmp(other.force())
+                } else {
+                    self.value_type_id().cmp(&other.value_type_id())
+                }
+            }
         }
-    }
+    };
 }
 
+#[cfg(all(not(feature = "hash"), not(feature = "ord")))]
+blanket_dyn_object!();
+#[cfg(all(feature = "hash", not(feature = "ord")))]
+blanket_dyn_object!(Hash +);
+#[cfg(all(not(feature = "hash"), feature = "ord"))]
+blanket_dyn_object!(Ord +);
+#[cfg(all(feature = "hash", feature = "ord"))]
+blanket_dyn_object!(Hash + Ord +);
+
 /// A type that can converted to and from [`Object`].
 pub trait AsObject: Sized + Debug + Clone + Send + Sync + 'static {
     fn cloned(obj: &Object) -> Option<Self>;
@@ -57,7 +118,8 @@ impl<T> AsObject for T where T: DynObject + Clone {
             if obj.is_none() { return None; };
             Some((obj as &dyn Any).downcast_ref::<T>().unwrap().clone())
         } else {
-            obj.0.as_ref().and_then(|x| x.downcast_ref::<T>().cloned())
+            obj.0.as_ref().and_then(|x| x.downcast_ref::<T>().cloned()
+                .or_else(|| x.as_ref().as_any().downcast_ref::<Lazy<T>>().map(|x| x.force().clone())))
         }
     }
 
@@ -66,25 +128,37 @@ impl<T> AsObject for T where T: DynObject + Clone {
             if obj.is_none() { return None; };
             Some((obj as &dyn Any).downcast_ref::<T>().unwrap())
         } else {
-            obj.0.as_ref().and_then(|x| x.downcast_ref())
+            obj.0.as_ref().and_then(|x| x.downcast_ref::<T>()
+                .or_else(|| x.as_ref().as_any().downcast_ref::<Lazy<T>>().map(Lazy::force)))
         }
     }
-    
+
     fn get_mut(obj: &mut Object) -> Option<&mut Self> {
         if TypeId::of::<T>() == TypeId::of::<Object>() {
             if obj.is_none() { return None; };
             Some((obj as &mut dyn Any).downcast_mut::<T>().unwrap())
         } else {
+            // A lazy slot is forced and replaced in place so the mutable borrow points at storage.
+            if obj.0.as_ref().is_some_and(|x| x.as_ref().as_any().downcast_ref::<Lazy<T>>().is_some()) {
+                if let Some(x) = obj.0.take() {
+                    if let Ok(lazy) = x.into_any().downcast::<Lazy<T>>() {
+                        obj.0 = Some(Box::new(lazy.into_value()));
+                    }
+                }
+            }
             obj.0.as_mut().and_then(|x| x.downcast_mut())
         }
     }
-    
+
     fn from_object(obj: Object) -> Option<Self> {
         if TypeId::of::<T>() == TypeId::of::<Object>() {
             if obj.is_none() { return None; };
             Some(*(Box::new(obj) as Box<dyn Any>).downcast::<T>().unwrap())
         } else {
-            obj.0.and_then(|x| x.downcast().map(|x| *x).ok())
+            obj.0.and_then(|x| match x.into_any().downcast::<T>() {
+                Ok(x) => Some(*x),
+                Err(x) => x.downcast::<Lazy<T>>().ok().map(|x| x.into_value()),
+            })
         }
     }
 
@@ -110,10 +184,260 @@ impl<T> AsObject for T where T: DynObject + Clone {
     }
 }
 
+#[doc(hidden)]
+pub use inventory;
+
+/// An upcaster from a boxed concrete value to a particular trait object `T` (e.g. `dyn Display`).
+///
+/// Registered via [`register_trait!`] and looked up by [`Object::downcast_trait`].
+pub struct Caster<T: ?Sized + 'static> {
+    /// Upcast `&dyn Any` (known to be the registered concrete type) to `&T`.
+    pub cast_ref: fn(&dyn Any) -> &T,
+    /// Upcast `&mut dyn Any` (known to be the registered concrete type) to `&mut T`.
+    pub cast_mut: fn(&mut dyn Any) -> &mut T,
+}
+
+/// A type-erased [`Caster`] entry collected by [`inventory`], keyed by the stored value's
+/// [`TypeId`] and the target trait object's [`TypeId`].
+#[doc(hidden)]
+pub struct TraitCaster {
+    pub source: fn() -> TypeId,
+    pub target: fn() -> TypeId,
+    pub caster: fn() -> Box<dyn Any + Send + Sync>,
+}
+
+inventory::collect!(TraitCaster);
+
+fn trait_registry() -> &'static HashMap<(TypeId, TypeId), Box<dyn Any + Send + Sync>> {
+    static REGISTRY: OnceLock<HashMap<(TypeId, TypeId), Box<dyn Any + Send + Sync>>> = OnceLock::new();
+    REGISTRY.get_or_init(|| {
+        let mut map = HashMap::new();
+        for entry in inventory::iter::<TraitCaster> {
+            map.insert(((entry.source)(), (entry.target)()), (entry.caster)());
+        }
+        map
+    })
+}
+
+/// Register that a stored `$ty` can be retrieved as `&dyn $trait` via [`Object::downcast_trait`].
+///
+/// ```
+/// # use dyn_object::{Object, register_trait};
+/// use std::fmt::Display;
+/// register_trait!(i32 as dyn Display);
+/// let obj = Object::new(5i32);
+/// assert_eq!(obj.downcast_trait::<dyn Display>().unwrap().to_string(), "5");
+/// assert!(obj.downcast_trait::<dyn std::fmt::LowerHex>().is_none());
+/// ```
+#[macro_export]
+macro_rules! register_trait {
+    ($ty:ty as dyn $tr:path) => {
+        $crate::inventory::submit! {
+            $crate::TraitCaster {
+                source: || ::core::any::TypeId::of::<$ty>(),
+                target: || ::core::any::TypeId::of::<dyn $tr>(),
+                caster: || ::std::boxed::Box::new($crate::Caster::<dyn $tr> {
+                    cast_ref: |any| any.downcast_ref::<$ty>().unwrap() as &dyn $tr,
+                    cast_mut: |any| any.downcast_mut::<$ty>().unwrap() as &mut dyn $tr,
+                }),
+            }
+        }
+    };
+}
+
+/// A deferred value that materializes and caches its result on first access.
+///
+/// Stored behind a [`Box<dyn DynObject>`] by [`Object::lazy`]; its `Debug`, equality, cloning
+/// (and hashing/ordering under the respective features) all force the thunk so in these respects
+/// a lazy object behaves like an eagerly constructed one; [`Object::downcast_trait`] still sees
+/// the wrapper type.
+struct Lazy<T> {
+    cell: OnceLock<T>,
+    init: Mutex<Option<Box<dyn FnOnce() -> T + Send + Sync>>>,
+}
+
+impl<T> Lazy<T> {
+    fn new(parse: impl FnOnce() -> T + Send + Sync + 'static) -> Self {
+        Lazy { cell: OnceLock::new(), init: Mutex::new(Some(Box::new(parse))) }
+    }
+
+    /// Return the value, running the thunk on first call.
+    fn force(&self) -> &T {
+        self.cell.get_or_init(|| {
+            let parse = self.init.lock().unwrap().take().expect("lazy value already forced");
+            parse()
+        })
+    }
+
+    /// Consume the slot, forcing the thunk if it hasn't run yet.
+    fn into_value(self) -> T {
+        match self.cell.into_inner() {
+            Some(value) => value,
+            None => (self.init.into_inner().unwrap().expect("lazy value already forced"))(),
+        }
+    }
+}
+
+impl<T: Debug> Debug for Lazy<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Debug::fmt(self.force(), f)
+    }
+}
+
+// A lazy value forces itself before delegating, so it carries the same bounds (per feature) as the
+// blanket [`DynObject`] impl — hence the same per-combination expansion.
+macro_rules! impl_lazy {
+    ($($bound:ident +)*) => {
+        impl<T> DynObject for Lazy<T> where T: Debug + Clone + PartialEq $(+ $bound)* + Send + Sync + 'static {
+            fn dyn_clone(&self) -> Box<dyn DynObject> {
+                Box::new(self.force().clone())
+            }
+
+            fn dyn_eq(&self, other: &dyn DynObject) -> bool {
+                let this = self.force();
+                match other.downcast_ref::<T>() {
+                    Some(other) => other == this,
+                    None => other.downcast_ref::<Lazy<T>>().is_some_and(|other| other.force() == this),
+                }
+            }
+
+            #[cfg(feature = "hash")]
+            fn dyn_hash(&self, mut state: &mut dyn Hasher) {
+                TypeId::of::<T>().hash(&mut state);
+                self.force().hash(&mut state);
+            }
+
+            #[cfg(feature = "ord")]
+            fn dyn_cmp(&self, other: &dyn DynObject) -> Ordering {
+                let this = self.force();
+                if let Some(other) = other.downcast_ref::<T>() {
+                    this.cmp(other)
+                } else if let Some(other) = other.downcast_ref::<Lazy<T>>() {
+                    this.cmp(other.force())
+                } else {
+                    self.value_type_id().cmp(&other.value_type_id())
+                }
+            }
+
+            #[cfg(feature = "ord")]
+            fn value_type_id(&self) -> TypeId {
+                // Key on the materialized type, not `Lazy<T>`, so a deferred value orders the
+                // same as its eager counterpart against unrelated types.
+                TypeId::of::<T>()
+            }
+        }
+
+        impl Object {
+            /// Create an object from a deferred computation, run and cached on first matching access.
+            ///
+            /// The thunk runs at most once, on the first [`get_ref`](Object::get_ref),
+            /// [`cloned`](Object::cloned), [`get_mut`](Object::get_mut) or [`take`](Object::take) for
+            /// its type; `Debug`, `PartialEq` and `Clone` force it as needed, so the result matches an
+            /// eagerly constructed object.
+            ///
+            /// ```
+            /// # use dyn_object::Object;
+            /// use std::sync::atomic::{AtomicUsize, Ordering};
+            /// static CALLS: AtomicUsize = AtomicUsize::new(0);
+            /// let obj = Object::lazy(|| { CALLS.fetch_add(1, Ordering::SeqCst); 42i32 });
+            /// assert_eq!(obj.get_ref::<i32>(), Some(&42));
+            /// assert_eq!(obj.cloned::<i32>(), Some(42));
+            /// assert_eq!(obj, Object::new(42));
+            /// assert_eq!(Object::new(42), obj);
+            /// assert_eq!(CALLS.load(Ordering::SeqCst), 1);
+            /// ```
+            pub fn lazy<T>(parse: impl FnOnce() -> T + Send + Sync + 'static) -> Self
+            where T: Debug + Clone + PartialEq $(+ $bound)* + Send + Sync + 'static {
+                Object(Some(Box::new(Lazy::new(parse))))
+            }
+        }
+    };
+}
+
+#[cfg(all(not(feature = "hash"), not(feature = "ord")))]
+impl_lazy!();
+#[cfg(all(feature = "hash", not(feature = "ord")))]
+impl_lazy!(Hash +);
+#[cfg(all(not(feature = "hash"), feature = "ord"))]
+impl_lazy!(Ord +);
+#[cfg(all(feature = "hash", feature = "ord"))]
+impl_lazy!(Hash + Ord +);
+
 /// A boxed type erased nullable dynamic object.
 #[derive(Debug, Default, Clone, PartialEq)]
 pub struct Object(Option<Box<dyn DynObject>>);
 
+/// Mirrors `Hash for Box<T>`, hashing a `None`/`Some` discriminant followed by the inner value.
+///
+/// Requires the `hash` feature.
+#[cfg(feature = "hash")]
+impl Hash for Object {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        match &self.0 {
+            None => state.write_u8(0),
+            Some(inner) => {
+                state.write_u8(1);
+                inner.dyn_hash(state);
+            }
+        }
+    }
+}
+
+/// Requires the `ord` feature.
+///
+/// # Caveat: `unnameable` is not reflexive
+///
+/// `Eq` nominally promises `a == a`, but an [`Object::unnameable`] is deliberately never equal
+/// to anything, itself included (its [`PartialEq`] always returns `false`). This impl therefore
+/// ships a known violation of reflexivity for that one case; code relying on `Eq` reflexivity
+/// (e.g. dedup) will treat two `unnameable` objects — and even the same one twice — as distinct.
+/// Every other value is fully reflexive.
+#[cfg(feature = "ord")]
+impl Eq for Object {}
+
+/// A total order over heterogeneous objects, ordering `None` before any `Some` and delegating
+/// `Some` vs `Some` to [`DynObject::dyn_cmp`]. Consistent with [`PartialEq`]: `a.cmp(b)` is
+/// `Equal` exactly when `a == b`. Requires the `ord` feature.
+///
+/// A lazy and an eager object of the same value compare equal to each other and order
+/// identically against any third type, so mixing them in a `BTreeSet`/`BTreeMap` is sound:
+///
+/// ```
+/// # use dyn_object::Object;
+/// let eager = Object::new(1i32);
+/// let lazy = Object::lazy(|| 1i32);
+/// assert_eq!(eager.cmp(&lazy), std::cmp::Ordering::Equal);
+/// let other = Object::new("x");
+/// assert_eq!(other.cmp(&eager), other.cmp(&lazy));
+/// assert_eq!(eager.cmp(&other), lazy.cmp(&other));
+/// ```
+///
+/// # Caveat: `unnameable` breaks the `Ord`↔`Eq` contract
+///
+/// Because [`Object::unnameable`] is never equal to itself (see the [`Eq`] impl), its `cmp`
+/// returns [`Equal`](Ordering::Equal) while `==` returns `false`, so for that value the
+/// documented "`a.cmp(b)` is `Equal` exactly when `a == b`" guarantee does not hold. This is an
+/// accepted trade so the `ord` feature can cover every other type; avoid storing `unnameable`
+/// objects in ordered collections.
+#[cfg(feature = "ord")]
+impl Ord for Object {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (&self.0, &other.0) {
+            (None, None) => Ordering::Equal,
+            (None, Some(_)) => Ordering::Less,
+            (Some(_), None) => Ordering::Greater,
+            (Some(a), Some(b)) => a.dyn_cmp(b.as_ref()),
+        }
+    }
+}
+
+#[cfg(feature = "ord")]
+impl PartialOrd for Object {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
 impl Object {
     /// A `None` object.
     /// 
@@ -140,6 +464,33 @@ impl Object {
                 false
             }
         }
+
+        // The `hash` feature adds a `Hash` bound to the blanket `DynObject` impl, so the boxed
+        // value must be hashable; the type is a singleton, so its hash carries no information.
+        #[cfg(feature = "hash")]
+        impl Hash for UnnameableUnequal {
+            fn hash<H: Hasher>(&self, _: &mut H) {}
+        }
+
+        // Likewise the `ord` feature adds an `Ord` bound (and therefore `Eq`). `PartialEq` is
+        // deliberately non-reflexive, so the `Eq` impl is nominal; the singleton orders `Equal`
+        // with itself, which is all the blanket impl's `downcast_ref` path can observe.
+        #[cfg(feature = "ord")]
+        impl Eq for UnnameableUnequal {}
+
+        #[cfg(feature = "ord")]
+        impl PartialOrd for UnnameableUnequal {
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        #[cfg(feature = "ord")]
+        impl Ord for UnnameableUnequal {
+            fn cmp(&self, _: &Self) -> Ordering {
+                Ordering::Equal
+            }
+        }
         Self(Some(Box::new(UnnameableUnequal)))
     }
 
@@ -268,6 +619,44 @@ impl Object {
         }
     }
 
+    /// Retrieve the inner value as a registered trait object.
+    ///
+    /// Returns `None` if the object is `None`, or if the stored type was never paired with `T`
+    /// through [`register_trait!`].
+    ///
+    /// This does not see through a lazy slot: an object built with [`Object::lazy`] is keyed by
+    /// its deferred wrapper rather than the parsed type, so a `register_trait!(T as dyn Trait)`
+    /// registration for the eventual value does not match. [`get_ref`](Object::get_ref) and
+    /// [`cloned`](Object::cloned) cache the parsed value inside the wrapper and leave it in
+    /// place; only [`get_mut`](Object::get_mut) replaces the wrapper with the plain value, after
+    /// which the registration matches.
+    ///
+    /// ```
+    /// # use dyn_object::{Object, register_trait};
+    /// use std::fmt::Display;
+    /// register_trait!(&'static str as dyn Display);
+    /// assert_eq!(Object::new("Ferris").downcast_trait::<dyn Display>().unwrap().to_string(), "Ferris");
+    /// assert!(Object::NONE.downcast_trait::<dyn Display>().is_none());
+    /// ```
+    pub fn downcast_trait<T: ?Sized + 'static>(&self) -> Option<&T> {
+        let inner = self.0.as_ref()?;
+        let any = inner.as_ref().as_any();
+        let key = (any.type_id(), TypeId::of::<T>());
+        let caster = trait_registry().get(&key)?.downcast_ref::<Caster<T>>()?;
+        Some((caster.cast_ref)(any))
+    }
+
+    /// Retrieve the inner value as a mutable registered trait object.
+    ///
+    /// See [`Object::downcast_trait`].
+    pub fn downcast_trait_mut<T: ?Sized + 'static>(&mut self) -> Option<&mut T> {
+        let inner = self.0.as_mut()?;
+        let any = inner.as_mut().as_any_mut();
+        let key = ((*any).type_id(), TypeId::of::<T>());
+        let caster = trait_registry().get(&key)?.downcast_ref::<Caster<T>>()?;
+        Some((caster.cast_mut)(any))
+    }
+
     /// Compare Object to a value that can be converted to an object.
     /// 
     /// ```